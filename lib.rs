@@ -7,8 +7,96 @@ mod simple_contract {
 
     use ink_storage::{traits::SpreadAllocate, Mapping};
     use ink_prelude::vec::Vec;
-    use ink_prelude::vec;
-    use ink_prelude::{string::String, format};
+
+    /// Identifies a listing within the marketplace.
+    pub type ListingId = u32;
+
+    /// Identifies a role that can be granted to an account.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum RoleId {
+        /// May list assets for sale and settle trades.
+        Seller,
+    }
+
+    /// Lifecycle state of a listing.
+    #[derive(Debug, scale::Encode, scale::Decode, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum ListingStatus {
+        /// Open for deposits and awaiting settlement.
+        Open,
+        /// Settled; the asset has changed hands and funds have moved.
+        Settled,
+    }
+
+    /// A single seller/buyer escrow hosted by this contract.
+    #[derive(Debug, scale::Encode, scale::Decode, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct Listing {
+        pub seller: AccountId,
+        pub asset: Hash,
+        pub price: Balance,
+        pub buyer: Option<AccountId>,
+        pub escrow: Balance,
+        pub status: ListingStatus,
+    }
+
+    /// Emitted when a seller lists an asset for sale.
+    #[ink(event)]
+    pub struct AssetListed {
+        #[ink(topic)]
+        listing_id: ListingId,
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        asset: Hash,
+        price: Balance,
+    }
+
+    /// Emitted when a buyer deposits funds into escrow.
+    #[ink(event)]
+    pub struct FundsDeposited {
+        #[ink(topic)]
+        listing_id: ListingId,
+        #[ink(topic)]
+        buyer: AccountId,
+        amount: Balance,
+        new_balance: Balance,
+    }
+
+    /// Emitted when a trade is settled between seller and buyer.
+    #[ink(event)]
+    pub struct Settled {
+        #[ink(topic)]
+        listing_id: ListingId,
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        asset: Hash,
+        price: Balance,
+    }
+
+    /// Errors that can occur when interacting with this contract.
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        /// The buyer's deposited funds do not cover the listed price.
+        NotEnoughFund,
+        /// The seller is not allowed to act as a buyer.
+        SellerCannotBuy,
+        /// There is no open listing for the given id.
+        AssetNotAvailable,
+        /// The caller does not hold the role required for this action.
+        NotAuthorized,
+        /// A native balance transfer failed.
+        TransferFailed,
+        /// The caller has no deposited funds to withdraw.
+        NothingToWithdraw,
+        /// The listing already holds a different buyer's escrow.
+        ListingReserved,
+    }
 
     /// Defines the storage of your contract.
     /// Add new fields to the below struct in order
@@ -16,151 +104,209 @@ mod simple_contract {
     #[ink(storage)]
     #[derive(SpreadAllocate)]
     pub struct SimpleContract {
-        /// Stores a fund of seller on the storage.
-        seller_fund: Mapping<AccountId, Balance>,
-        /// Stores a asset of seller on the storage.
-        seller_asset: Mapping<AccountId, Vec<(Hash, Balance)>>,
-        /// Stores a fund of buyer on the storage.
-        buyer_fund: Mapping<AccountId, Balance>,
-        /// Stores a asset of buyer on the storage.
-        buyer_asset: Mapping<AccountId, Vec<(Hash, Balance)>>,
-        /// Seller
-        seller: AccountId,
-        /// Buyer
-        buyer: AccountId,
-        /// Money
-        price: Balance,
-        /// Asset
-        asset: Hash,
+        /// Listings keyed by id, supporting many concurrent seller/buyer escrows.
+        listings: Mapping<ListingId, Listing>,
+        /// The id the next listing created by `create_listing` will receive.
+        next_id: ListingId,
+        /// Account allowed to grant/revoke roles and transfer ownership.
+        owner: AccountId,
+        /// Roles granted to accounts, e.g. `(RoleId::Seller, alice) -> ()`.
+        roles: Mapping<(RoleId, AccountId), ()>,
     }
 
     impl SimpleContract {
+        /// Constructor that initializes the marketplace contract.
         #[ink(constructor)]
-        /// Constructor that initializes the sell contract.
-        pub fn new_sell(init_item: Hash, init_price: Balance) -> Self {
+        pub fn new() -> Self {
             ink_lang::utils::initialize_contract(|contract: &mut Self| {
-                contract.asset = init_item;
-                contract.price = init_price;
                 let caller = Self::env().caller();
-                contract.seller = caller;
-                let value = vec![(contract.asset, contract.price)];
-                contract.seller_asset.insert(&caller, &value);
+                contract.owner = caller;
+                contract.roles.insert(&(RoleId::Seller, caller), &());
+                contract.next_id = 0;
             })
         }
-    
-        /// Default initializes the contract.
-        #[ink(constructor)]
-        pub fn sell_default() -> Self {
-            // Even though we're not explicitly initializing the `Mapping`,
-            // we still need to call this
-            ink_lang::utils::initialize_contract(|contract: &mut Self| {
-                contract.asset = Hash::default();
-                contract.price = Default::default();
-            })
+
+        /// Returns `Err(Error::NotAuthorized)` unless the caller is the contract owner.
+        fn ensure_owner(&self) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotAuthorized);
+            }
+            Ok(())
         }
 
-        /// Seller add asset
+        /// Grants `role` to `account`. Callable by the owner only.
         #[ink(message)]
-        pub fn insert_asset(&mut self, item: Hash, price: Balance) {
-            let caller = self.env().caller();
-            let asset = item;
-            let price = price;
-            let _x = self.get_asset_data(caller);
-            if self.seller_asset.contains(&caller) {
-                panic!("Asset exists");
-                ink_env::debug_println!("Asset exists");
-            } else {
-                self.seller_asset.insert(&caller, &vec![(asset, price)])
-            } 
-        } 
-        
-        /// Simply returns the current asset of seller.
+        pub fn grant_role(&mut self, role: RoleId, account: AccountId) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.roles.insert(&(role, account), &());
+            Ok(())
+        }
+
+        /// Revokes `role` from `account`. Callable by the owner only.
         #[ink(message)]
-        pub fn get_asset_data(&self, id: AccountId) -> Option<Vec<(Hash, Balance)>> {
-            self.seller_asset.get(&id)
+        pub fn revoke_role(&mut self, role: RoleId, account: AccountId) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.roles.remove(&(role, account));
+            Ok(())
         }
 
-        /// Check current fund of buyer
+        /// Returns whether `account` has been granted `role`.
         #[ink(message)]
-        pub fn check_fund(&self, id: AccountId) -> Option<Balance> {
-            self.buyer_fund.get(&id)
+        pub fn has_role(&self, role: RoleId, account: AccountId) -> bool {
+            self.roles.contains(&(role, account))
         }
 
+        /// Transfers contract ownership to `new_owner`. Callable by the owner only.
         #[ink(message)]
-        pub fn total_status(&self, id: AccountId) -> String {
-            if id == self.seller {
-                match self.seller_asset.get(&id) {
-                    Some(x) => {
-                        let item = x[0].0;
-                        let price = self.seller_asset.get(&id).unwrap_or_default()[0].1;
-                        let fund = self.seller_fund.get(&id).unwrap_or_default();
-                        format!("Current item: {:?}. Current price: {}. Fund: {}", item, price, fund)
-                    },
-                    None => {
-                        let fund = self.seller_fund.get(&id).unwrap_or_default();
-                        format!("No item data. Fund: {}",fund)
-                    },
-                }
-            } else if id == self.buyer {
-                match self.buyer_asset.get(&id) {
-                    Some(x) => {
-                        let item = x[0].0;
-                        let price = self.buyer_asset.get(&id).unwrap_or_default()[0].1;
-                        let fund = self.buyer_fund.get(&id).unwrap_or_default();
-                        format!("Current item: {:?}. Current price: {}. Fund: {}", item, price, fund)
-                    },
-                    None => {
-                        let fund = self.buyer_fund.get(&id).unwrap_or_default();
-                        format!("No item data. Fund: {}",fund)
-                    },
-                }
-            } else {
-                format!("No data")
-            }      
+        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.owner = new_owner;
+            Ok(())
         }
 
-        /// Buyer deposit money first time or next times
+        /// Lists `asset` for `price`, opening a new escrow. Callable by a seller only.
         #[ink(message)]
-        pub fn buyer_deposit_money(&mut self, id: AccountId, money: Balance) {
+        pub fn create_listing(&mut self, asset: Hash, price: Balance) -> Result<ListingId, Error> {
             let caller = self.env().caller();
-            let fund = self.buyer_fund.get(&caller).unwrap_or_default() + money;
-            self.buyer_fund.remove(&caller);
-            self.buyer_fund.insert(&caller, &fund);
-            if caller == id { panic!("You own your asset") };
-            if caller == self.seller { panic!("Seller can't do this") };
-            self.buyer = caller;
-            if !self.seller_asset.contains(&id) { panic!("Not available yet")};
+            if !self.has_role(RoleId::Seller, caller) {
+                return Err(Error::NotAuthorized);
+            }
+            let listing_id = self.next_id;
+            self.next_id += 1;
+            self.listings.insert(
+                listing_id,
+                &Listing {
+                    seller: caller,
+                    asset,
+                    price,
+                    buyer: None,
+                    escrow: 0,
+                    status: ListingStatus::Open,
+                },
+            );
+            self.env().emit_event(AssetListed {
+                listing_id,
+                seller: caller,
+                asset,
+                price,
+            });
+            Ok(listing_id)
+        }
 
+        /// Returns the listing stored under `listing_id`, if any.
+        #[ink(message)]
+        pub fn get_listing(&self, listing_id: ListingId) -> Option<Listing> {
+            self.listings.get(listing_id)
         }
 
-        /// Settle the contract when asset from seller & money from buyer was set in. Then terminate contract
+        /// Returns the open listings among ids `start..start + len`, in id order. Ids in
+        /// that window whose listing is settled are skipped, so fewer than `len` entries
+        /// can be returned even when open listings exist beyond the window.
         #[ink(message)]
-        pub fn settle(&mut self, id: AccountId) {
+        pub fn list_open(&self, start: ListingId, len: u32) -> Vec<(ListingId, Listing)> {
+            let end = start.saturating_add(len).min(self.next_id);
+            (start..end)
+                .filter_map(|id| self.listings.get(id).map(|listing| (id, listing)))
+                .filter(|(_, listing)| listing.status == ListingStatus::Open)
+                .collect()
+        }
+
+        /// Buyer deposit money first time or next times. The deposited amount is the
+        /// value transferred along with the call and accumulates in the listing's escrow.
+        #[ink(message, payable)]
+        pub fn buyer_deposit_money(&mut self, listing_id: ListingId) -> Result<(), Error> {
             let caller = self.env().caller();
-            assert!(self.seller_asset.contains(&caller) && self.buyer_fund.contains(&id), "No asset or fund");
-            let item = self.seller_asset.get(&caller).unwrap()[0].0;
-            let price = self.seller_asset.get(&caller).unwrap()[0].1;
+            let mut listing = self.listings.get(listing_id).ok_or(Error::AssetNotAvailable)?;
+            if listing.status != ListingStatus::Open {
+                return Err(Error::AssetNotAvailable);
+            }
+            if caller == listing.seller {
+                return Err(Error::SellerCannotBuy);
+            }
+            if let Some(existing_buyer) = listing.buyer {
+                if existing_buyer != caller {
+                    return Err(Error::ListingReserved);
+                }
+            }
+
+            let money = self.env().transferred_value();
+            listing.buyer = Some(caller);
+            listing.escrow += money;
+            let new_balance = listing.escrow;
+            self.listings.insert(listing_id, &listing);
+
+            self.env().emit_event(FundsDeposited {
+                listing_id,
+                buyer: caller,
+                amount: money,
+                new_balance,
+            });
+            Ok(())
+        }
 
-            let fund = self.buyer_fund.get(&id).unwrap();
-            if fund < price {
-                panic!("Not enough fund");
+        /// Lets the current buyer reclaim their deposited escrow while a listing is still open.
+        #[ink(message)]
+        pub fn withdraw(&mut self, listing_id: ListingId) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let mut listing = self.listings.get(listing_id).ok_or(Error::AssetNotAvailable)?;
+            if listing.buyer != Some(caller) || listing.escrow == 0 {
+                return Err(Error::NothingToWithdraw);
             }
+            let amount = listing.escrow;
 
-            let money = fund - price;
-            self.buyer_asset.insert(&id, &vec![(item, price)]);
-            match money {
-                x if x > 0 => {
-                    self.buyer_fund.remove(&id);
-                    self.buyer_fund.insert(&id, &x);
-                },
-                _ => self.buyer_fund.remove(&id),
-            };
-            self.seller_asset.remove(caller);
-            self.seller_fund.insert(&caller, &price);
-            self.env().terminate_contract(self.env().caller());
+            self.env()
+                .transfer(caller, amount)
+                .map_err(|_| Error::TransferFailed)?;
 
+            listing.escrow = 0;
+            listing.buyer = None;
+            self.listings.insert(listing_id, &listing);
+            Ok(())
         }
 
+        /// Settles a listing once its escrow covers the price: pays the seller, refunds any
+        /// remainder to the buyer, and marks the listing settled. Callable by the seller only.
+        #[ink(message)]
+        pub fn settle(&mut self, listing_id: ListingId) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if !self.has_role(RoleId::Seller, caller) {
+                return Err(Error::NotAuthorized);
+            }
+            let mut listing = self.listings.get(listing_id).ok_or(Error::AssetNotAvailable)?;
+            if listing.seller != caller || listing.status != ListingStatus::Open {
+                return Err(Error::AssetNotAvailable);
+            }
+            let buyer = listing.buyer.ok_or(Error::AssetNotAvailable)?;
+            if listing.escrow < listing.price {
+                return Err(Error::NotEnoughFund);
+            }
+
+            let change = listing.escrow - listing.price;
+            let (seller, asset, price) = (listing.seller, listing.asset, listing.price);
+
+            self.env()
+                .transfer(seller, price)
+                .map_err(|_| Error::TransferFailed)?;
+            if change > 0 {
+                self.env()
+                    .transfer(buyer, change)
+                    .map_err(|_| Error::TransferFailed)?;
+            }
+
+            listing.status = ListingStatus::Settled;
+            listing.buyer = Some(buyer);
+            listing.escrow = 0;
+            self.listings.insert(listing_id, &listing);
+
+            self.env().emit_event(Settled {
+                listing_id,
+                seller,
+                buyer,
+                asset,
+                price,
+            });
+            Ok(())
+        }
     }
 
     /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
@@ -186,60 +332,241 @@ mod simple_contract {
             default_accounts().bob
         }
 
+        fn set_caller(caller: AccountId) {
+            ink_env::test::set_caller::<Environment>(caller);
+        }
+
         fn item(data: [u8; 32]) -> Hash {
             ink_env::Hash::from(data)
         }
 
-        /// We test if the default constructor does its job. Then add new asset to default
+        /// We test that a fresh contract starts with no open listings and that
+        /// creating one makes it visible through `get_listing` and `list_open`.
         #[ink::test]
-        fn default_sell() {
-            let caller = alice();
-            let mut contract = SimpleContract::sell_default();
-            assert_eq!(contract.get_asset_data(caller), None);
+        fn create_listing_works() {
+            let mut contract = SimpleContract::new();
+            assert_eq!(contract.list_open(0, 10), vec![]);
 
-            let price = 20;
-            contract.insert_asset(item([0; 32]), price);
-            assert_eq!(contract.get_asset_data(caller), Some(vec![(item([0; 32]), price)]));
+            let price: Balance = 450;
+            let listing_id = contract.create_listing(item([1; 32]), price).unwrap();
+            assert_eq!(listing_id, 0);
+            let listing = contract.get_listing(listing_id).unwrap();
+            assert_eq!(listing.seller, alice());
+            assert_eq!(listing.price, price);
+            assert_eq!(listing.status, ListingStatus::Open);
+            assert_eq!(contract.list_open(0, 10), vec![(listing_id, listing)]);
         }
 
-        /// We test new deploy of our contract.
+        /// We test listing several assets from the same seller.
         #[ink::test]
-        fn creat_new_sell() {
-            let price: Balance = 450;
-            let contract = SimpleContract::new_sell(item([1;32]), price);
-            let caller = alice();
-            assert_eq!(contract.get_asset_data(caller), Some(vec![(item([1;32]), price)]));
-            assert!(contract.seller == alice())
-        }    
-        
-        /// We test insert asset to contract where another asset already existed
+        fn create_listing_allows_multiple_listings() {
+            let mut contract = SimpleContract::new();
+            let first = contract.create_listing(item([1; 32]), 100).unwrap();
+            let second = contract.create_listing(item([2; 32]), 200).unwrap();
+            assert_eq!(first, 0);
+            assert_eq!(second, 1);
+            assert_eq!(contract.list_open(0, 10).len(), 2);
+        }
+
+        /// We test that a seller cannot deposit into their own listing.
         #[ink::test]
-        #[should_panic]
-        fn insert_asset_fail() {
-            let price: Balance = 450;
-            let mut contract = SimpleContract::new_sell(item([1; 32]), price);
-            contract.insert_asset(item([2; 32]), price)
+        fn buyer_deposit_money_rejects_seller() {
+            let mut contract = SimpleContract::new();
+            let listing_id = contract.create_listing(item([1; 32]), 450).unwrap();
+            let result = contract.buyer_deposit_money(listing_id);
+            assert_eq!(result, Err(Error::SellerCannotBuy));
         }
 
+        /// We test that a second account cannot piggyback on another buyer's escrow to drain it.
         #[ink::test]
-        fn test_settle() {
-            let caller = alice();
+        fn buyer_deposit_money_rejects_other_buyer() {
+            let mut contract = SimpleContract::new();
+            let listing_id = contract.create_listing(item([1; 32]), 450).unwrap();
+
+            set_caller(bob());
+            ink_env::test::set_value_transferred::<Environment>(600);
+            contract.buyer_deposit_money(listing_id).unwrap();
+
+            let eve = default_accounts().eve;
+            set_caller(eve);
+            ink_env::test::set_value_transferred::<Environment>(0);
+            let result = contract.buyer_deposit_money(listing_id);
+            assert_eq!(result, Err(Error::ListingReserved));
+
+            let withdraw_result = contract.withdraw(listing_id);
+            assert_eq!(withdraw_result, Err(Error::NothingToWithdraw));
+        }
+
+        /// We test the full deposit and settle flow across two accounts.
+        #[ink::test]
+        fn deposit_and_settle_works() {
+            let mut contract = SimpleContract::new();
             let price: Balance = 450;
-            let mut contract = SimpleContract::new_sell(item([1; 32]), price);
-            assert_eq!(contract.get_asset_data(caller), Some(vec![(item([1; 32]), price)]));
-            assert!(contract.seller_asset.contains(&caller));
-            contract.buyer_deposit_money(alice(), 600);
-            // contract.settle(alice());
-            // assert_eq!(contract.buyer_asset.get(&bob()).unwrap(), vec![(item([1; 32]), price)]);
+            let listing_id = contract.create_listing(item([1; 32]), price).unwrap();
+
+            set_caller(bob());
+            ink_env::test::set_value_transferred::<Environment>(600);
+            contract.buyer_deposit_money(listing_id).unwrap();
+
+            set_caller(alice());
+            contract.settle(listing_id).unwrap();
+
+            let listing = contract.get_listing(listing_id).unwrap();
+            assert_eq!(listing.status, ListingStatus::Settled);
+            assert_eq!(listing.escrow, 0);
+            assert_eq!(contract.list_open(0, 10).len(), 0);
         }
 
+        /// We test that settling without enough escrow fails.
         #[ink::test]
-        fn test_deposit() {
+        fn settle_fails_when_not_enough_fund() {
+            let mut contract = SimpleContract::new();
+            let listing_id = contract.create_listing(item([1; 32]), 450).unwrap();
+
+            set_caller(bob());
+            ink_env::test::set_value_transferred::<Environment>(100);
+            contract.buyer_deposit_money(listing_id).unwrap();
+
+            set_caller(alice());
+            let result = contract.settle(listing_id);
+            assert_eq!(result, Err(Error::NotEnoughFund));
+        }
+
+        /// We test that the owner can grant and revoke roles.
+        #[ink::test]
+        fn owner_can_grant_and_revoke_role() {
+            let mut contract = SimpleContract::new();
+            assert!(!contract.has_role(RoleId::Seller, bob()));
+
+            contract.grant_role(RoleId::Seller, bob()).unwrap();
+            assert!(contract.has_role(RoleId::Seller, bob()));
+
+            contract.revoke_role(RoleId::Seller, bob()).unwrap();
+            assert!(!contract.has_role(RoleId::Seller, bob()));
+        }
+
+        /// We test that a non-owner cannot grant roles.
+        #[ink::test]
+        fn non_owner_cannot_grant_role() {
+            let mut contract = SimpleContract::new();
+            set_caller(bob());
+            let result = contract.grant_role(RoleId::Seller, bob());
+            assert_eq!(result, Err(Error::NotAuthorized));
+        }
+
+        /// We test that a non-owner cannot revoke roles.
+        #[ink::test]
+        fn non_owner_cannot_revoke_role() {
+            let mut contract = SimpleContract::new();
+            contract.grant_role(RoleId::Seller, bob()).unwrap();
+            set_caller(bob());
+            let result = contract.revoke_role(RoleId::Seller, bob());
+            assert_eq!(result, Err(Error::NotAuthorized));
+        }
+
+        /// We test that a non-owner cannot transfer ownership.
+        #[ink::test]
+        fn non_owner_cannot_transfer_ownership() {
+            let mut contract = SimpleContract::new();
+            set_caller(bob());
+            let result = contract.transfer_ownership(bob());
+            assert_eq!(result, Err(Error::NotAuthorized));
+        }
+
+        /// We test that an account without the seller role cannot create a listing.
+        #[ink::test]
+        fn non_seller_cannot_create_listing() {
+            let mut contract = SimpleContract::new();
+            set_caller(bob());
+            let result = contract.create_listing(item([1; 32]), 450);
+            assert_eq!(result, Err(Error::NotAuthorized));
+        }
+
+        /// We test that an account without the seller role cannot settle a listing,
+        /// even one that isn't theirs.
+        #[ink::test]
+        fn non_seller_cannot_settle() {
+            let mut contract = SimpleContract::new();
+            let listing_id = contract.create_listing(item([1; 32]), 450).unwrap();
+
+            set_caller(bob());
+            let result = contract.settle(listing_id);
+            assert_eq!(result, Err(Error::NotAuthorized));
+        }
+
+        /// We test that `create_listing` emits an `AssetListed` event with the right fields.
+        #[ink::test]
+        fn create_listing_emits_asset_listed_event() {
+            let mut contract = SimpleContract::new();
+            let asset = item([1; 32]);
+            let listing_id = contract.create_listing(asset, 450).unwrap();
+
+            let events = ink_env::test::recorded_events().collect::<Vec<_>>();
+            assert_eq!(events.len(), 1);
+            let decoded = match <Event as scale::Decode>::decode(&mut &events[0].data[..])
+                .expect("invalid event data")
+            {
+                Event::AssetListed(event) => event,
+                _ => panic!("expected an AssetListed event"),
+            };
+            assert_eq!(decoded.listing_id, listing_id);
+            assert_eq!(decoded.seller, alice());
+            assert_eq!(decoded.asset, asset);
+            assert_eq!(decoded.price, 450);
+        }
+
+        /// We test that `buyer_deposit_money` emits a `FundsDeposited` event with the right fields.
+        #[ink::test]
+        fn buyer_deposit_money_emits_funds_deposited_event() {
+            let mut contract = SimpleContract::new();
+            let listing_id = contract.create_listing(item([1; 32]), 450).unwrap();
+
+            set_caller(bob());
+            ink_env::test::set_value_transferred::<Environment>(600);
+            contract.buyer_deposit_money(listing_id).unwrap();
+
+            let events = ink_env::test::recorded_events().collect::<Vec<_>>();
+            assert_eq!(events.len(), 2);
+            let decoded = match <Event as scale::Decode>::decode(&mut &events[1].data[..])
+                .expect("invalid event data")
+            {
+                Event::FundsDeposited(event) => event,
+                _ => panic!("expected a FundsDeposited event"),
+            };
+            assert_eq!(decoded.listing_id, listing_id);
+            assert_eq!(decoded.buyer, bob());
+            assert_eq!(decoded.amount, 600);
+            assert_eq!(decoded.new_balance, 600);
+        }
+
+        /// We test that `settle` emits a `Settled` event with the right fields.
+        #[ink::test]
+        fn settle_emits_settled_event() {
+            let mut contract = SimpleContract::new();
+            let asset = item([1; 32]);
             let price: Balance = 450;
-            let mut contract = SimpleContract::new_sell(item([1; 32]), price);
-            let caller = bob();
-            contract.buyer_deposit_money(alice(), 600);
-            assert!(contract.seller != alice())
+            let listing_id = contract.create_listing(asset, price).unwrap();
+
+            set_caller(bob());
+            ink_env::test::set_value_transferred::<Environment>(600);
+            contract.buyer_deposit_money(listing_id).unwrap();
+
+            set_caller(alice());
+            contract.settle(listing_id).unwrap();
+
+            let events = ink_env::test::recorded_events().collect::<Vec<_>>();
+            assert_eq!(events.len(), 3);
+            let decoded = match <Event as scale::Decode>::decode(&mut &events[2].data[..])
+                .expect("invalid event data")
+            {
+                Event::Settled(event) => event,
+                _ => panic!("expected a Settled event"),
+            };
+            assert_eq!(decoded.listing_id, listing_id);
+            assert_eq!(decoded.seller, alice());
+            assert_eq!(decoded.buyer, bob());
+            assert_eq!(decoded.asset, asset);
+            assert_eq!(decoded.price, price);
         }
     }
 }